@@ -52,9 +52,89 @@
 
 use heck::ToUpperCamelCase;
 use proc_macro::TokenStream;
-use proc_macro2::Span;
-use quote::quote;
-use syn::{Ident, ItemEnum, Signature};
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{Expr, Ident, ItemEnum, Path, Signature, Token};
+
+// one or more function signatures, separated by `;`, as given to the attribute macro
+struct Signatures(Punctuated<Signature, Token![;]>);
+
+impl Parse for Signatures {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Signatures(Punctuated::parse_terminated(input)?))
+    }
+}
+
+// the attribute either defines a fresh trait from its signatures, or dispatches onto an
+// existing trait given by path: `impl path::to::MyTrait { fn run(self) ... }`
+enum DispatchAttr {
+    NewTrait(Signatures),
+    ExistingTrait { trait_path: Path, signatures: Signatures },
+}
+
+impl Parse for DispatchAttr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(Token![impl]) {
+            input.parse::<Token![impl]>()?;
+            let trait_path: Path = input.parse()?;
+            let content;
+            syn::braced!(content in input);
+            let signatures: Signatures = content.parse()?;
+            Ok(DispatchAttr::ExistingTrait {
+                trait_path,
+                signatures,
+            })
+        } else {
+            Ok(DispatchAttr::NewTrait(input.parse()?))
+        }
+    }
+}
+
+// the argument of a `#[dispatch(skip = <expr>)]` variant attribute: either a single expression
+// that stands in for whichever signature is being dispatched (only valid when the macro was
+// given a single signature), or one expression per signature, keyed by method name, needed as
+// soon as there's more than one signature since their return types may differ
+enum SkipArg {
+    Single(Expr),
+    PerMethod(Vec<(Ident, Expr)>),
+}
+
+impl Parse for SkipArg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident: Ident = input.parse()?;
+        if ident != "skip" {
+            return Err(syn::Error::new(ident.span(), "expected `skip`"));
+        }
+        if input.peek(syn::token::Paren) {
+            let content;
+            syn::parenthesized!(content in input);
+            let entries = Punctuated::<MethodSkip, Token![,]>::parse_terminated(&content)?;
+            Ok(SkipArg::PerMethod(
+                entries.into_iter().map(|entry| (entry.method, entry.expr)).collect(),
+            ))
+        } else {
+            input.parse::<Token![=]>()?;
+            Ok(SkipArg::Single(input.parse()?))
+        }
+    }
+}
+
+// one `<method> = <expr>` entry of a `#[dispatch(skip(...))]` attribute
+struct MethodSkip {
+    method: Ident,
+    expr: Expr,
+}
+
+impl Parse for MethodSkip {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let method: Ident = input.parse()?;
+        input.parse::<Token![=]>()?;
+        let expr: Expr = input.parse()?;
+        Ok(MethodSkip { method, expr })
+    }
+}
 
 /// The main macro.
 ///
@@ -96,150 +176,630 @@ use syn::{Ident, ItemEnum, Signature};
 ///    }
 ///    ```
 ///
+/// Both the enum and the signature may be generic. \
+/// The enum's generics are reproduced on the `impl`, and a bound `FieldTy: Trait` is added for the
+/// type of every field that is dispatched onto, the same way `derive` auto-bounds its type parameters
+/// (unit variants and `#[dispatch(skip = ...)]` variants get no bound).
+///
+/// You can also pass several signatures, separated by `;`. \
+/// They all end up as methods of the *same* generated trait (named after the first signature),
+/// and the dispatching `impl` matches over the variants once per method.
+/// ```
+/// #[clap_dispatch(fn validate(&self); fn run(self) -> Result<()>)]
+/// enum MyCommand {
+///   Foo(FooArgs)
+///   Bar(BarArgs)
+/// }
+/// ```
+///
+/// If you already have a trait you want to dispatch onto (your own, or one from another crate),
+/// you can target it by path instead of defining a new one: \
+/// `#[clap_dispatch(impl path::to::MyTrait { fn run(self) })]`. \
+/// In this mode only the dispatching `impl path::to::MyTrait for MyCommand` is generated,
+/// no trait definition.
+///
+/// A signature doesn't need a `self` either. \
+/// For static/associated-function dispatch, give it a `&MyCommand` discriminator argument instead,
+/// e.g. `fn from_config(cmd: &MyCommand, cfg: &Config) -> Self`. \
+/// The discriminator is only used to pick the variant, it's dropped from the generated trait method
+/// (here: `fn from_config(cfg: &Config) -> Self;`), and the dispatch itself is generated as an
+/// inherent `impl MyCommand { ... }` instead of a trait `impl`, since there is no `self` to match on.
+/// Because such a signature *constructs* the variant rather than matching on one that already
+/// exists, it can only be dispatched to a variant whose field is its only field — there is no
+/// enum value lying around to fill in any other fields from. \
+/// It also can't be combined with targeting an existing trait: dropping the discriminator only
+/// works for a trait this macro defines itself, since an existing trait's method keeps whatever
+/// signature it was actually declared with.
+///
+/// Variants aren't restricted to a single unnamed field either:
+/// - A tuple variant with several fields binds them as `args0, args1, ...` and dispatches on the
+///   one marked `#[dispatch]` (defaulting to the sole field when there's only one).
+/// - A named-field variant binds by field name, and dispatches on the field marked `#[dispatch]`
+///   (again defaulting to the sole field).
+/// - A unit variant has nothing to dispatch onto, so it either needs a
+///   `#[dispatch(skip = <expr>)]` attribute giving a fixed value, or the method must return `()`,
+///   in which case a no-op is generated. For a receiverless signature, a unit variant without
+///   `#[dispatch(skip = ...)]` just constructs itself.
+///
+/// `#[dispatch(skip = <expr>)]` also works on non-unit variants, to opt a variant out of
+/// delegation entirely and return a fixed value instead. \
+/// A single expression only type-checks if there's a single signature, since its value has to
+/// fit that signature's return type; as soon as several signatures are being dispatched, give
+/// one expression per method instead: `#[dispatch(skip(validate = false, run = Ok(())))]`.
 #[proc_macro_attribute]
-pub fn clap_dispatch(attr: TokenStream, mut item: TokenStream) -> TokenStream {
-    let generated =
-        clap_dispatch_gen(&attr, &item).unwrap_or_else(|error| error.to_compile_error().into());
-    item.extend(generated);
-    item
+pub fn clap_dispatch(attr: TokenStream, item: TokenStream) -> TokenStream {
+    // the enum may fail to parse at all, in which case we have nothing better to re-emit than
+    // the original item, `#[dispatch]` attributes and all
+    let mut item_enum: ItemEnum = match syn::parse(item.clone()) {
+        Ok(item_enum) => item_enum,
+        Err(error) => {
+            let mut item = item;
+            item.extend(TokenStream::from(error.to_compile_error()));
+            return item;
+        }
+    };
+
+    let generated = clap_dispatch_gen(&attr, &item_enum)
+        .unwrap_or_else(|error| TokenStream::from(error.to_compile_error()));
+
+    // `#[dispatch]` is only a helper attribute understood by this macro, not a real one rustc
+    // knows about (unlike a `derive`, an attribute macro doesn't get to register inert helper
+    // attributes), so it must not survive into the enum we re-emit, whether or not the rest of
+    // the macro succeeded
+    strip_dispatch_attrs(&mut item_enum);
+
+    let mut output = TokenStream::from(quote! { #item_enum });
+    output.extend(generated);
+    output
 }
 
-fn clap_dispatch_gen(attr: &TokenStream, item: &TokenStream) -> Result<TokenStream, syn::Error> {
-    // parse the enum and the attribute
-    let item_enum: ItemEnum = syn::parse(item.clone())?;
-    let signature: Signature = syn::parse(attr.clone())?;
+fn clap_dispatch_gen(attr: &TokenStream, item_enum: &ItemEnum) -> Result<TokenStream, syn::Error> {
+    let dispatch_attr: DispatchAttr = syn::parse(attr.clone())?;
+
+    let (trait_path, signatures) = match dispatch_attr {
+        DispatchAttr::NewTrait(signatures) => (None, signatures),
+        DispatchAttr::ExistingTrait {
+            trait_path,
+            signatures,
+        } => (Some(trait_path), signatures),
+    };
+
+    // generate the new things which should be appended after the enum
+    generate(item_enum, signatures.0.into_iter().collect(), trait_path)
+}
 
-    // generate new things which should be appended after the enum
-    generate(item_enum, signature)
+// removes the `#[dispatch]` / `#[dispatch(skip = ...)]` helper attributes from every variant
+// and field, so they don't end up in the enum we re-emit
+fn strip_dispatch_attrs(item_enum: &mut ItemEnum) {
+    for variant in item_enum.variants.iter_mut() {
+        variant.attrs.retain(|attr| !attr.path().is_ident("dispatch"));
+        for field in variant.fields.iter_mut() {
+            field.attrs.retain(|attr| !attr.path().is_ident("dispatch"));
+        }
+    }
 }
 
 // generates both:
-// 1. the new trait whose only function is given by the provided signature
+// 1. the new trait whose functions are given by the provided signatures (skipped if an
+//    existing trait path was given instead)
 // 2. the implementation of this trait for the enum
 fn generate(
     // the enum on which the attribute macro was placed
-    item_enum: ItemEnum,
-    // the function signature that was provided with the attribute macro
-    signature: Signature,
+    item_enum: &ItemEnum,
+    // the function signatures that were provided with the attribute macro
+    signatures: Vec<Signature>,
+    // an existing trait to dispatch onto instead of defining a new one
+    trait_path: Option<Path>,
 ) -> Result<TokenStream, syn::Error> {
     // make sure the user provided everything in the correct form
-    validity_checks(&item_enum, &signature)?;
+    validity_checks(item_enum, &signatures, trait_path.is_some())?;
 
     // relevant identifiers
-    let enum_ident = item_enum.ident;
-    let signature_ident = &signature.ident;
-    let trait_ident = upper_camel_case(signature_ident);
+    let enum_ident = &item_enum.ident;
 
-    // the arguments which need to be passed to the function, except `self`
-    let call_args = signature.inputs.iter().skip(1).map(|fn_arg| {
-        if let syn::FnArg::Typed(pat_type) = fn_arg {
-            &pat_type.pat
-        } else {
-            // all functions arguments except the first one should be FnArg::Typed (not FnArg::Receiver)
-            unreachable!()
+    // the trait to target: either an existing one given by path, or a fresh one named
+    // after the first signature, just like a single-signature invocation
+    let trait_definition;
+    let trait_path = match trait_path {
+        Some(trait_path) => {
+            trait_definition = None;
+            trait_path
         }
-    });
+        None => {
+            let trait_ident = upper_camel_case(&signatures[0].ident);
+            trait_definition = Some(trait_ident.clone());
+            syn::parse_quote!(self::#trait_ident)
+        }
+    };
 
-    // the match arms for the implementation of the trait
-    let match_arms = item_enum.variants.into_iter().map(|variant| {
-        let variant_ident = variant.ident;
-        let call_args = call_args.clone();
+    // the enum's own generics need to be reproduced on the `impl`, so that the
+    // dispatch also works for generic enums (the trait itself stays non-generic,
+    // since the methods keep whatever generics they were declared with)
+    let mut generics = item_enum.generics.clone();
+    {
+        // every variant that actually dispatches onto a field needs that field's type to
+        // implement the generated trait, the same way a classic `derive` auto-bounds its
+        // type parameters (variants that are `#[dispatch(skip = ...)]` or unit don't dispatch
+        // onto anything, so they don't need a bound)
+        let where_clause = generics.make_where_clause();
+        for variant in item_enum.variants.iter() {
+            if let Dispatch::Field { ty, .. } = variant_dispatch(variant) {
+                where_clause
+                    .predicates
+                    .push(syn::parse_quote!(#ty: #trait_path));
+            }
+        }
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
+    // a signature either takes `self` (dispatched by matching on the receiver) or, for
+    // static/associated-function dispatch, takes a `&Enum` discriminator as its first
+    // argument instead (dispatched by matching on that discriminator)
+    let (instance_signatures, static_signatures): (Vec<_>, Vec<_>) = signatures
+        .iter()
+        .partition(|signature| is_receiver(signature));
+
+    // the trait definition is only emitted when no existing trait path was given; static
+    // signatures lose their discriminator argument here, since the field types' impls
+    // of the trait don't know about the enum
+    let trait_methods = instance_signatures
+        .iter()
+        .map(|signature| quote! { #signature; })
+        .chain(static_signatures.iter().map(|signature| {
+            let reduced = without_first_arg(signature);
+            quote! { #reduced; }
+        }));
+    let trait_definition = trait_definition.map(|trait_ident| {
         quote! {
-            Self::#variant_ident(args) => self::#trait_ident::#signature_ident(args, #(#call_args),*),
+            trait #trait_ident {
+                #(#trait_methods)*
+            }
         }
     });
 
-    // the final generated code
-    let generated = quote! {
-        trait #trait_ident {
-            #signature;
-        }
+    // instance methods dispatch by matching on `self`, and are implemented as part of
+    // `impl #trait_path for #enum_ident`
+    let instance_impl_methods = instance_signatures.iter().map(|signature| {
+        let signature_ident = &signature.ident;
+        let call_args = call_args(signature);
+
+        let match_arms = item_enum.variants.iter().map(|variant| {
+            let pattern = variant_pattern(variant, true);
+            let call_args = call_args.clone();
+
+            let value = match variant_dispatch(variant) {
+                Dispatch::Skip(skip_arg) => {
+                    let expr = skip_expr_for(&skip_arg, signature);
+                    quote! { #expr }
+                }
+                Dispatch::Unit => {
+                    // validity_checks already made sure this method returns `()` whenever
+                    // a unit variant isn't marked `#[dispatch(skip = ...)]`
+                    unit_default(signature).expect("validated")
+                }
+                Dispatch::Field { expr, .. } => {
+                    quote! { #trait_path::#signature_ident(#expr, #(#call_args),*) }
+                }
+            };
+
+            quote! { #pattern => #value, }
+        });
 
-        impl #trait_ident for #enum_ident {
+        quote! {
+            #[allow(unused_variables)]
             #signature {
                 match self {
                     #(#match_arms)*
                 }
             }
         }
+    });
+    let trait_impl = (!instance_signatures.is_empty()).then(|| {
+        quote! {
+            impl #impl_generics #trait_path for #enum_ident #ty_generics #where_clause {
+                #(#instance_impl_methods)*
+            }
+        }
+    });
+
+    // static methods can't match on `self` (there is none), so they dispatch by matching
+    // on their discriminator argument instead, and are implemented as inherent functions
+    // on the enum rather than as part of the trait `impl`
+    let static_impl_methods = static_signatures.iter().map(|signature| {
+        let signature_ident = &signature.ident;
+        let discriminator = discriminator_pat(signature);
+        let call_args = call_args(signature);
+
+        let match_arms = item_enum.variants.iter().map(|variant| {
+            let variant_ident = &variant.ident;
+            let pattern = variant_pattern(variant, false);
+            let call_args = call_args.clone();
+
+            let value = match variant_dispatch(variant) {
+                Dispatch::Skip(skip_arg) => {
+                    let expr = skip_expr_for(&skip_arg, signature);
+                    quote! { #expr }
+                }
+                // there's no field to construct, so the unit variant just reconstructs itself
+                Dispatch::Unit => quote! { Self::#variant_ident },
+                // unlike instance dispatch, there's no existing enum value to read the other
+                // fields from, so `validity_checks` already made sure the dispatched field is
+                // this variant's only field; construct it with the field's own shape (tuple or
+                // named) rather than assuming tuple-call syntax
+                Dispatch::Field { ty, .. } => {
+                    let call = quote! { <#ty as #trait_path>::#signature_ident(#(#call_args),*) };
+                    match &variant.fields {
+                        syn::Fields::Named(fields) => {
+                            let field_ident = fields.named[0].ident.as_ref().unwrap();
+                            quote! { Self::#variant_ident { #field_ident: #call } }
+                        }
+                        _ => quote! { Self::#variant_ident(#call) },
+                    }
+                }
+            };
+
+            quote! { #pattern => #value, }
+        });
+
+        quote! {
+            #[allow(unused_variables)]
+            #signature {
+                match #discriminator {
+                    #(#match_arms)*
+                }
+            }
+        }
+    });
+    let inherent_impl = (!static_signatures.is_empty()).then(|| {
+        quote! {
+            impl #impl_generics #enum_ident #ty_generics #where_clause {
+                #(#static_impl_methods)*
+            }
+        }
+    });
+
+    // the final generated code
+    let generated = quote! {
+        #trait_definition
+        #trait_impl
+        #inherent_impl
     };
 
     Ok(generated.into())
 }
 
-fn upper_camel_case(ident: &Ident) -> Ident {
-    let new_ident = ident.to_string().to_upper_camel_case();
-    Ident::new(&new_ident, Span::call_site())
+// the arguments which need to be passed on to the trait method, i.e. all arguments
+// except the first one (`self` or the discriminator)
+fn call_args(signature: &Signature) -> impl Iterator<Item = &syn::Pat> + Clone {
+    signature.inputs.iter().skip(1).map(|fn_arg| {
+        if let syn::FnArg::Typed(pat_type) = fn_arg {
+            &*pat_type.pat
+        } else {
+            // all function arguments except the first one should be FnArg::Typed (not FnArg::Receiver)
+            unreachable!()
+        }
+    })
 }
 
-fn validity_checks(item_enum: &ItemEnum, signature: &Signature) -> Result<(), syn::Error> {
-    // make sure the enum doesn't use generics
-    if item_enum.generics.lt_token.is_some() {
-        return Err(syn::Error::new_spanned(
-            &item_enum.generics,
-            "generics are not yet supported by clap-dispatch",
-        ));
+// whether the signature's first argument is some form of `self`
+fn is_receiver(signature: &Signature) -> bool {
+    matches!(signature.inputs.first(), Some(syn::FnArg::Receiver(_)))
+}
+
+// the pattern of the discriminator argument, i.e. the first argument of a receiverless signature
+fn discriminator_pat(signature: &Signature) -> &syn::Pat {
+    match signature.inputs.first() {
+        Some(syn::FnArg::Typed(pat_type)) => &pat_type.pat,
+        _ => unreachable!(), // ruled out by validity_checks
     }
+}
 
-    // make sure signature has no generics
-    if signature.generics.lt_token.is_some() {
-        return Err(syn::Error::new_spanned(
-            &signature.generics,
-            "generics are not yet supported by clap-dispatch",
-        ));
+// the signature with its first argument (`self` or the discriminator) removed
+fn without_first_arg(signature: &Signature) -> Signature {
+    let mut reduced = signature.clone();
+    reduced.inputs = reduced.inputs.into_iter().skip(1).collect();
+    reduced
+}
+
+// what a variant dispatches onto: a fixed, user-provided value (`#[dispatch(skip = ...)]`),
+// nothing at all (a plain unit variant), or one of its fields
+enum Dispatch<'a> {
+    Skip(SkipArg),
+    Unit,
+    Field { expr: TokenStream2, ty: &'a syn::Type },
+}
+
+// determines what a variant dispatches onto; assumes `validity_checks` already ran
+fn variant_dispatch(variant: &syn::Variant) -> Dispatch<'_> {
+    if let Some(skip_arg) = variant_skip(variant).expect("validated") {
+        return Dispatch::Skip(skip_arg);
     }
 
-    // make sure signature has no variadic
-    if signature.variadic.is_some() {
-        return Err(syn::Error::new_spanned(
-            &signature.variadic,
-            "variadics are not yet supported by clap-dispatch",
-        ));
+    let syn::Fields::Unit = &variant.fields else {
+        let index = dispatch_field_index(&variant.fields).expect("validated");
+        let field = variant.fields.iter().nth(index).unwrap();
+        let expr = match field.ident {
+            Some(ref field_ident) => quote! { #field_ident },
+            None => {
+                let arg_ident = format_ident!("args{index}");
+                quote! { #arg_ident }
+            }
+        };
+        return Dispatch::Field {
+            expr,
+            ty: &field.ty,
+        };
+    };
+
+    Dispatch::Unit
+}
+
+// the pattern that matches a variant, without (`bind_fields: false`) or with (`true`) binding
+// each of its fields by name (`argsN` for unnamed fields, the field name for named ones)
+fn variant_pattern(variant: &syn::Variant, bind_fields: bool) -> TokenStream2 {
+    let variant_ident = &variant.ident;
+    match &variant.fields {
+        syn::Fields::Unit => quote! { Self::#variant_ident },
+        syn::Fields::Unnamed(fields) if bind_fields => {
+            let idents = (0..fields.unnamed.len()).map(|i| format_ident!("args{i}"));
+            quote! { Self::#variant_ident(#(#idents),*) }
+        }
+        syn::Fields::Unnamed(_) => quote! { Self::#variant_ident(..) },
+        syn::Fields::Named(fields) if bind_fields => {
+            let idents = fields.named.iter().map(|field| field.ident.as_ref().unwrap());
+            quote! { Self::#variant_ident { #(#idents),* } }
+        }
+        syn::Fields::Named(_) => quote! { Self::#variant_ident { .. } },
     }
+}
 
-    // make sure first argument of signature is some form of `self`
-    match signature.inputs.first() {
-        Some(fn_arg) => {
-            if !matches!(fn_arg, syn::FnArg::Receiver(_)) {
-                return Err(syn::Error::new_spanned(
-                    fn_arg,
-                    "first argument of function must be `self` or `&self` or `&mut self`",
-                ));
+// parses the `#[dispatch(skip = ...)]` / `#[dispatch(skip(...))]` attribute on a variant, if present
+fn variant_skip(variant: &syn::Variant) -> Result<Option<SkipArg>, syn::Error> {
+    for attr in &variant.attrs {
+        if attr.path().is_ident("dispatch") {
+            let skip_arg: SkipArg = attr.parse_args().map_err(|_| {
+                syn::Error::new_spanned(
+                    attr,
+                    "`#[dispatch]` on a variant must be `#[dispatch(skip = <expr>)]` or, when \
+                     several signatures are being dispatched, \
+                     `#[dispatch(skip(<method> = <expr>, ...))]`",
+                )
+            })?;
+            return Ok(Some(skip_arg));
+        }
+    }
+    Ok(None)
+}
+
+// resolves the fixed value a variant's `#[dispatch(skip = ...)]` attribute provides for a
+// specific signature; assumes `validity_checks` already made sure a `Single` expression is
+// only used with a single signature, and that a `PerMethod` one covers every signature
+fn skip_expr_for<'a>(skip_arg: &'a SkipArg, signature: &Signature) -> &'a Expr {
+    match skip_arg {
+        SkipArg::Single(expr) => expr,
+        SkipArg::PerMethod(entries) => {
+            &entries
+                .iter()
+                .find(|(method, _)| *method == signature.ident)
+                .expect("validated")
+                .1
+        }
+    }
+}
+
+// finds the index of the field marked `#[dispatch]`, defaulting to the sole field if there's
+// only one and none is marked
+fn dispatch_field_index(fields: &syn::Fields) -> Result<usize, syn::Error> {
+    let marked: Vec<usize> = fields
+        .iter()
+        .enumerate()
+        .filter(|(_, field)| field.attrs.iter().any(|attr| attr.path().is_ident("dispatch")))
+        .map(|(index, _)| index)
+        .collect();
+
+    match marked.as_slice() {
+        [] if fields.is_empty() => Err(syn::Error::new_spanned(
+            fields,
+            "a variant with no fields has nothing to dispatch onto; use a unit variant instead, optionally with `#[dispatch(skip = ...)]`",
+        )),
+        [] if fields.len() == 1 => Ok(0),
+        [] => Err(syn::Error::new_spanned(
+            fields,
+            "a variant with more than one field needs a `#[dispatch]` attribute marking which field to dispatch on",
+        )),
+        [index] => Ok(*index),
+        _ => Err(syn::Error::new_spanned(
+            fields,
+            "at most one field may be marked `#[dispatch]`",
+        )),
+    }
+}
+
+// whether a signature's return type is literally `Self`
+fn is_self_type(output: &syn::ReturnType) -> bool {
+    match output {
+        syn::ReturnType::Type(_, ty) => matches!(
+            &**ty,
+            syn::Type::Path(type_path) if type_path.qself.is_none() && type_path.path.is_ident("Self")
+        ),
+        syn::ReturnType::Default => false,
+    }
+}
+
+// the expression to fall back to for a unit variant whose signature returns `()`, if any
+fn unit_default(signature: &Signature) -> Option<TokenStream2> {
+    match &signature.output {
+        syn::ReturnType::Default => Some(quote! { () }),
+        syn::ReturnType::Type(_, ty) => match &**ty {
+            syn::Type::Tuple(tuple) if tuple.elems.is_empty() => Some(quote! { () }),
+            _ => None,
+        },
+    }
+}
+
+// makes sure a variant's `#[dispatch(skip = ...)]` attribute actually covers every signature
+// it stands in for, so `skip_expr_for` can't fail at codegen time: a `Single` expression can't
+// type-check for signatures with different return types, so it's only allowed when there's
+// exactly one signature; a `PerMethod` one needs exactly one entry per signature
+fn validate_skip_arg(
+    skip_arg: &SkipArg,
+    signatures: &[Signature],
+    variant: &syn::Variant,
+) -> Result<(), syn::Error> {
+    match skip_arg {
+        SkipArg::Single(_) if signatures.len() == 1 => Ok(()),
+        SkipArg::Single(_) => Err(syn::Error::new_spanned(
+            &variant.ident,
+            "this variant needs one expression per signature, since more than one signature is \
+             being dispatched; use `#[dispatch(skip(<method> = <expr>, ...))]` instead",
+        )),
+        SkipArg::PerMethod(entries) => {
+            for signature in signatures {
+                if !entries.iter().any(|(method, _)| *method == signature.ident) {
+                    return Err(syn::Error::new_spanned(
+                        &variant.ident,
+                        format!(
+                            "this variant's `#[dispatch(skip(...))]` has no entry for `{}`",
+                            signature.ident
+                        ),
+                    ));
+                }
+            }
+            for (method, _) in entries {
+                if !signatures.iter().any(|signature| signature.ident == *method) {
+                    return Err(syn::Error::new_spanned(
+                        method,
+                        format!("`{method}` is not one of the dispatched signatures"),
+                    ));
+                }
             }
+            Ok(())
         }
-        None => {
+    }
+}
+
+fn upper_camel_case(ident: &Ident) -> Ident {
+    let new_ident = ident.to_string().to_upper_camel_case();
+    Ident::new(&new_ident, Span::call_site())
+}
+
+fn validity_checks(
+    item_enum: &ItemEnum,
+    signatures: &[Signature],
+    existing_trait: bool,
+) -> Result<(), syn::Error> {
+    // make sure at least one signature was given
+    if signatures.is_empty() {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            "clap_dispatch needs at least one function signature",
+        ));
+    }
+
+    for signature in signatures {
+        // make sure signature has no variadic
+        if signature.variadic.is_some() {
             return Err(syn::Error::new_spanned(
-                &signature.inputs,
-                "function needs at least a `self` argument (or `&self` or `&mut self`)",
-            ))
+                &signature.variadic,
+                "variadics are not yet supported by clap-dispatch",
+            ));
+        }
+
+        // make sure the first argument is either some form of `self`, or, for
+        // static/associated-function dispatch, a discriminator the impl can match over
+        match signature.inputs.first() {
+            Some(syn::FnArg::Receiver(_)) => {}
+            Some(syn::FnArg::Typed(pat_type)) => {
+                if !matches!(&*pat_type.pat, syn::Pat::Ident(_)) {
+                    return Err(syn::Error::new_spanned(
+                        &pat_type.pat,
+                        "discriminator argument of a receiverless function must be a plain identifier",
+                    ));
+                }
+                if !matches!(&*pat_type.ty, syn::Type::Reference(_)) {
+                    return Err(syn::Error::new_spanned(
+                        &pat_type.ty,
+                        "discriminator argument of a receiverless function must be a reference, e.g. `&Enum`",
+                    ));
+                }
+                // for an existing trait, the field types have to implement the trait's real
+                // method, discriminator argument and all, since `impl ExistingTrait { ... }`
+                // just mirrors that method's signature verbatim; the generated dispatch can
+                // only drop the discriminator for a trait it defined itself, where it's free
+                // to leave the argument out of the method it puts in the trait
+                if existing_trait {
+                    return Err(syn::Error::new_spanned(
+                        &signature.ident,
+                        "a receiverless (static/associated-function) signature can't be dispatched \
+                         onto an existing trait, since the field types would need to implement that \
+                         trait's method including the discriminator argument, which defeats the point \
+                         of the discriminator; give this signature a `self` receiver instead, or \
+                         define a fresh trait instead of targeting an existing one",
+                    ));
+                }
+                // the generated dispatch always constructs the enum back from the discriminator
+                // (`Self::Variant(<FieldTy as Trait>::method(...))`), so a receiverless signature
+                // that returns anything other than `Self` would leave that codegen producing a
+                // value of the wrong type, surfacing as a wall of confusing E0308s pointed at the
+                // macro invocation instead of at the actual mistake
+                if !is_self_type(&signature.output) {
+                    return Err(syn::Error::new_spanned(
+                        &signature.output,
+                        "a receiverless (static/associated-function) signature must return `Self`, \
+                         since the generated dispatch constructs the enum from its result",
+                    ));
+                }
+            }
+            None => {
+                return Err(syn::Error::new_spanned(
+                    &signature.inputs,
+                    "function needs at least a `self` argument, or a `&Enum` discriminator argument for static dispatch",
+                ))
+            }
         }
     }
 
-    // make sure the enum variants have exactly one unnamed field
+    // make sure every variant can actually be dispatched
     for variant in item_enum.variants.iter() {
+        // `#[dispatch(skip = ...)]` opts a variant out of delegation entirely, whatever its shape
+        if let Some(skip_arg) = variant_skip(variant)? {
+            validate_skip_arg(&skip_arg, signatures, variant)?;
+            continue;
+        }
+
         match &variant.fields {
-            syn::Fields::Named(fields_named) => {
-                return Err(syn::Error::new_spanned(
-                    fields_named,
-                    "must have unnamed field, not named",
-                ));
+            syn::Fields::Unit => {
+                // a unit variant has no field to dispatch onto, so every instance-style
+                // signature it's asked to implement must return `()`
+                for signature in signatures.iter().filter(|signature| is_receiver(signature)) {
+                    if unit_default(signature).is_none() {
+                        return Err(syn::Error::new_spanned(
+                            &variant.ident,
+                            format!(
+                                "unit variant needs `#[dispatch(skip = ...)]`, since `{}` doesn't return `()`",
+                                signature.ident
+                            ),
+                        ));
+                    }
+                }
             }
-            syn::Fields::Unnamed(fields_unnamed) => {
-                if fields_unnamed.unnamed.len() != 1 {
+            fields => {
+                dispatch_field_index(fields)?;
+
+                // a receiverless signature constructs the variant from scratch, so unlike
+                // instance dispatch there's no existing enum value to read the other fields
+                // from; the dispatched field must be the variant's only field
+                if fields.len() > 1 && signatures.iter().any(|signature| !is_receiver(signature)) {
                     return Err(syn::Error::new_spanned(
-                        fields_unnamed,
-                        "number of unnamed fields must be exactly one",
+                        &variant.ident,
+                        "a receiverless (static/associated-function) signature can only \
+                         construct a variant whose dispatched field is its only field, since \
+                         there's no existing enum value to read the other fields from; mark \
+                         this variant `#[dispatch(skip = ...)]` instead",
                     ));
                 }
             }
-            syn::Fields::Unit => {
-                return Err(syn::Error::new_spanned(
-                    &variant.ident,
-                    "variant must have an unnamed field",
-                ));
-            }
-        };
+        }
     }
 
     Ok(())
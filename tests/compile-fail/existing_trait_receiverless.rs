@@ -0,0 +1,23 @@
+// dropping the discriminator argument only works for a trait the macro defines itself; an
+// existing trait's method keeps its real signature, discriminator and all, so this combination
+// must be rejected rather than generating a call that's missing an argument.
+use clap_dispatch::clap_dispatch;
+
+trait Make {
+    fn make(cli: &Cli, n: i32) -> Self;
+}
+
+#[clap_dispatch(impl Make { fn make(cli: &Cli, n: i32) -> Self })]
+enum Cli {
+    Foo(FooArgs),
+}
+
+struct FooArgs;
+
+impl Make for FooArgs {
+    fn make(_cli: &Cli, _n: i32) -> Self {
+        FooArgs
+    }
+}
+
+fn main() {}
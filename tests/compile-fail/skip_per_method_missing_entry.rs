@@ -0,0 +1,23 @@
+// `#[dispatch(skip(...))]` needs exactly one entry per dispatched signature; `run` has none
+// here, so this must be rejected instead of leaving its match arm unfilled.
+use clap_dispatch::clap_dispatch;
+
+#[clap_dispatch(fn validate(&self) -> bool; fn run(self) -> Result<(), String>)]
+enum Cli {
+    Foo(FooArgs),
+    #[dispatch(skip(validate = false))]
+    Disabled,
+}
+
+struct FooArgs;
+
+impl Validate for FooArgs {
+    fn validate(&self) -> bool {
+        true
+    }
+    fn run(self) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+fn main() {}
@@ -0,0 +1,24 @@
+// a single `#[dispatch(skip = expr)]` expression can't type-check for every signature once more
+// than one is being dispatched, since their return types may differ; this must be rejected at
+// macro-expansion time rather than falling through to a confusing rustc type error.
+use clap_dispatch::clap_dispatch;
+
+#[clap_dispatch(fn validate(&self) -> bool; fn run(self) -> Result<(), String>)]
+enum Cli {
+    Foo(FooArgs),
+    #[dispatch(skip = true)]
+    Disabled,
+}
+
+struct FooArgs;
+
+impl Validate for FooArgs {
+    fn validate(&self) -> bool {
+        true
+    }
+    fn run(self) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+// a receiverless signature constructs the variant from scratch, so there's no existing enum
+// value to pull a second field's value from; this must be rejected rather than emitting a
+// construction that's missing an argument.
+use clap_dispatch::clap_dispatch;
+
+#[clap_dispatch(fn from_config(cli: &Cli, value: i32) -> Self)]
+enum Cli {
+    Foo(#[dispatch] FooArgs, String),
+}
+
+struct FooArgs {
+    value: i32,
+}
+
+impl FromConfig for FooArgs {
+    fn from_config(value: i32) -> Self {
+        FooArgs { value }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+// the generated dispatch always wraps a receiverless signature's result back into the enum
+// (`Self::Variant(<FieldTy as Trait>::method(...))`), so the signature must actually return
+// `Self`; otherwise the wrapping produces a value of the wrong type, and the error would
+// surface as a wall of confusing E0308s pointed at the macro invocation instead of at this.
+use clap_dispatch::clap_dispatch;
+
+#[clap_dispatch(fn weight(cli: &Cli) -> i32)]
+enum Cli {
+    Foo(FooArgs),
+}
+
+struct FooArgs;
+
+impl Weight for FooArgs {
+    fn weight(_cli: &Cli) -> i32 {
+        0
+    }
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+// a unit variant has nothing to dispatch onto, so it must either be `#[dispatch(skip = ...)]`
+// or the dispatched method must return `()`; here it doesn't, so this must be rejected.
+use clap_dispatch::clap_dispatch;
+
+#[clap_dispatch(fn run(self) -> i32)]
+enum Cli {
+    Idle,
+    Foo(FooArgs),
+}
+
+struct FooArgs;
+
+impl Run for FooArgs {
+    fn run(self) -> i32 {
+        1
+    }
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+// a tuple variant with more than one field needs a `#[dispatch]` attribute picking which field
+// to dispatch on; with none marked here, this must be rejected.
+use clap_dispatch::clap_dispatch;
+
+#[clap_dispatch(fn run(self) -> i32)]
+enum Cli {
+    Foo(FooArgs, String),
+}
+
+struct FooArgs;
+
+impl Run for FooArgs {
+    fn run(self) -> i32 {
+        1
+    }
+}
+
+fn main() {}
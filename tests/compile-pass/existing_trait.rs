@@ -0,0 +1,24 @@
+// `#[clap_dispatch(impl path::to::MyTrait { ... })]` dispatches onto a trait the caller already
+// owns instead of defining a fresh one, emitting only the dispatching `impl`.
+use clap_dispatch::clap_dispatch;
+
+trait Run {
+    fn run(self) -> i32;
+}
+
+#[clap_dispatch(impl Run { fn run(self) -> i32 })]
+enum Cli {
+    Foo(FooArgs),
+}
+
+struct FooArgs;
+
+impl Run for FooArgs {
+    fn run(self) -> i32 {
+        42
+    }
+}
+
+fn main() {
+    assert_eq!(Cli::Foo(FooArgs).run(), 42);
+}
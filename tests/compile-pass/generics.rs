@@ -0,0 +1,29 @@
+// the enum and the dispatched signature may both be generic: the enum's generics are
+// reproduced on the `impl`, and every dispatched field's type gets an auto-generated
+// `FieldTy: Trait` bound, the same way `derive` does it.
+use clap_dispatch::clap_dispatch;
+use std::fmt::Display;
+
+#[clap_dispatch(fn render<T: Display>(self, items: Vec<T>) -> String)]
+enum Cli<W> {
+    Csv(CsvArgs<W>),
+}
+
+struct CsvArgs<W> {
+    separator: W,
+}
+
+impl<W: Display> Render for CsvArgs<W> {
+    fn render<T: Display>(self, items: Vec<T>) -> String {
+        items
+            .into_iter()
+            .map(|item| item.to_string())
+            .collect::<Vec<_>>()
+            .join(&self.separator.to_string())
+    }
+}
+
+fn main() {
+    let cli = Cli::Csv(CsvArgs { separator: ',' });
+    assert_eq!(cli.render(vec![1, 2, 3]), "1,2,3");
+}
@@ -0,0 +1,37 @@
+// passing several signatures, separated by `;`, emits one trait containing all of them and a
+// dispatching `impl` that matches over the variants once per method.
+use clap_dispatch::clap_dispatch;
+
+#[clap_dispatch(fn validate(&self) -> bool; fn run(self) -> Result<(), String>)]
+enum Cli {
+    Foo(FooArgs),
+    Bar(BarArgs),
+}
+
+struct FooArgs;
+struct BarArgs;
+
+impl Validate for FooArgs {
+    fn validate(&self) -> bool {
+        true
+    }
+    fn run(self) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+impl Validate for BarArgs {
+    fn validate(&self) -> bool {
+        false
+    }
+    fn run(self) -> Result<(), String> {
+        Err("bar can't run".to_string())
+    }
+}
+
+fn main() {
+    assert!(Cli::Foo(FooArgs).validate());
+    assert!(Cli::Foo(FooArgs).run().is_ok());
+    assert!(!Cli::Bar(BarArgs).validate());
+    assert!(Cli::Bar(BarArgs).run().is_err());
+}
@@ -0,0 +1,28 @@
+// with several dispatched signatures whose return types differ, `#[dispatch(skip = ...)]` needs
+// one expression per method instead of a single one.
+use clap_dispatch::clap_dispatch;
+
+#[clap_dispatch(fn validate(&self) -> bool; fn run(self) -> Result<(), String>)]
+enum Cli {
+    Foo(FooArgs),
+    #[dispatch(skip(validate = false, run = Err("disabled".to_string())))]
+    Disabled,
+}
+
+struct FooArgs;
+
+impl Validate for FooArgs {
+    fn validate(&self) -> bool {
+        true
+    }
+    fn run(self) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+fn main() {
+    assert!(Cli::Foo(FooArgs).validate());
+    assert!(Cli::Foo(FooArgs).run().is_ok());
+    assert!(!Cli::Disabled.validate());
+    assert!(Cli::Disabled.run().is_err());
+}
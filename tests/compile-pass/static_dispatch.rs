@@ -0,0 +1,42 @@
+// a signature with no `self`, just a `&Enum` discriminator argument, dispatches as an inherent
+// associated function rather than a trait method, matching on the discriminator instead of self.
+use clap_dispatch::clap_dispatch;
+
+#[clap_dispatch(fn from_config(cli: &Cli, value: i32) -> Self)]
+enum Cli {
+    Foo(FooArgs),
+    Bar(BarArgs),
+}
+
+struct FooArgs {
+    value: i32,
+}
+struct BarArgs {
+    value: i32,
+}
+
+impl FromConfig for FooArgs {
+    fn from_config(value: i32) -> Self {
+        FooArgs { value }
+    }
+}
+
+impl FromConfig for BarArgs {
+    fn from_config(value: i32) -> Self {
+        BarArgs { value: value * 2 }
+    }
+}
+
+fn main() {
+    let foo = Cli::Foo(FooArgs { value: 0 });
+    match Cli::from_config(&foo, 7) {
+        Cli::Foo(args) => assert_eq!(args.value, 7),
+        Cli::Bar(_) => panic!("wrong variant"),
+    }
+
+    let bar = Cli::Bar(BarArgs { value: 0 });
+    match Cli::from_config(&bar, 7) {
+        Cli::Bar(args) => assert_eq!(args.value, 14),
+        Cli::Foo(_) => panic!("wrong variant"),
+    }
+}
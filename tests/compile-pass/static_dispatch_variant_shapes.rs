@@ -0,0 +1,42 @@
+// static/associated-function dispatch has to construct the variant itself, so it must respect
+// each variant's actual shape: tuple-call for an unnamed field, `{ field: ... }` for a named one.
+use clap_dispatch::clap_dispatch;
+
+#[clap_dispatch(fn from_config(cli: &Cli, value: i32) -> Self)]
+enum Cli {
+    Tuple(FooArgs),
+    Named { inner: BarArgs },
+}
+
+struct FooArgs {
+    value: i32,
+}
+struct BarArgs {
+    value: i32,
+}
+
+impl FromConfig for FooArgs {
+    fn from_config(value: i32) -> Self {
+        FooArgs { value }
+    }
+}
+
+impl FromConfig for BarArgs {
+    fn from_config(value: i32) -> Self {
+        BarArgs { value: value * 2 }
+    }
+}
+
+fn main() {
+    let tuple = Cli::Tuple(FooArgs { value: 0 });
+    match Cli::from_config(&tuple, 7) {
+        Cli::Tuple(args) => assert_eq!(args.value, 7),
+        Cli::Named { .. } => panic!("wrong variant"),
+    }
+
+    let named = Cli::Named { inner: BarArgs { value: 0 } };
+    match Cli::from_config(&named, 7) {
+        Cli::Named { inner } => assert_eq!(inner.value, 14),
+        Cli::Tuple(_) => panic!("wrong variant"),
+    }
+}
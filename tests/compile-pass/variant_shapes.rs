@@ -0,0 +1,35 @@
+// every variant shape dispatches: a unit variant via `#[dispatch(skip = ...)]`, a multi-field
+// tuple variant via the field marked `#[dispatch]`, and a named-field variant the same way.
+use clap_dispatch::clap_dispatch;
+
+#[clap_dispatch(fn run(self) -> i32)]
+enum Cli {
+    #[dispatch(skip = 0)]
+    Idle,
+    Tuple(#[dispatch] FooArgs, String),
+    Named {
+        #[dispatch]
+        inner: BarArgs,
+    },
+}
+
+struct FooArgs;
+struct BarArgs;
+
+impl Run for FooArgs {
+    fn run(self) -> i32 {
+        1
+    }
+}
+
+impl Run for BarArgs {
+    fn run(self) -> i32 {
+        2
+    }
+}
+
+fn main() {
+    assert_eq!(Cli::Idle.run(), 0);
+    assert_eq!(Cli::Tuple(FooArgs, "label".to_string()).run(), 1);
+    assert_eq!(Cli::Named { inner: BarArgs }.run(), 2);
+}
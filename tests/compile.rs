@@ -0,0 +1,10 @@
+// clap_dispatch's correctness lives almost entirely in what it expands to, so it's exercised
+// with trybuild instead of unit tests: every file under tests/compile-pass is expected to
+// compile (and, where it has a `fn main`, to run and assert on the dispatch behavior), and
+// every file under tests/compile-fail is expected to be rejected by a `validity_checks` error.
+#[test]
+fn compile_tests() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/compile-pass/*.rs");
+    t.compile_fail("tests/compile-fail/*.rs");
+}